@@ -26,6 +26,8 @@
 //!
 //! [`Tap`]: ./trait.Tap.html
 
+use core::ops::DerefMut;
+
 /// An interface to enable the `tap` operation which is implemented for all `Sized` types.
 ///
 /// The tap operation takes full ownership of a variable, calls the given function with a mutable
@@ -75,6 +77,90 @@ pub trait Tap {
     /// assert_eq!(max, 8);
     /// ```
     fn tap<F: FnOnce(&mut Self)>(self, f: F) -> Self;
+
+    /// Executes a closure on a shared reference to an object, returning it afterwards.
+    ///
+    /// Unlike [`tap`](Tap::tap), this only grants read access to the value, which makes it a
+    /// good fit for passive inspection points such as logging, metrics or asserts that should
+    /// not be able to mutate the value in an expression chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Tap;
+    ///
+    /// let mut log = Vec::new();
+    /// let data: [u32; 5] = [2, 8, 3, 4, 0];
+    /// assert_eq!(data.tap_ref(|x| log.push(x.len())), [2, 8, 3, 4, 0]);
+    /// assert_eq!(log, [5]);
+    /// ```
+    fn tap_ref<F: FnOnce(&Self)>(self, f: F) -> Self;
+
+    /// Like [`tap`](Tap::tap), but the closure is only called in debug builds.
+    ///
+    /// In release builds (more precisely, whenever `cfg!(debug_assertions)` is `false`) `f` is
+    /// never called and `self` is returned untouched, so this can be used to scatter
+    /// `dbg!`-style inspection or mutation points through a pipeline during development without
+    /// paying for them in release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Tap;
+    ///
+    /// let data: [u32; 5] = [2, 8, 3, 4, 0];
+    /// let tapped = data.tap_dbg(|x| x.sort());
+    /// if cfg!(debug_assertions) {
+    ///     assert_eq!(tapped, [0, 2, 3, 4, 8]);
+    /// } else {
+    ///     assert_eq!(tapped, [2, 8, 3, 4, 0]);
+    /// }
+    /// ```
+    fn tap_dbg<F: FnOnce(&mut Self)>(self, f: F) -> Self;
+
+    /// Like [`tap_ref`](Tap::tap_ref), but the closure is only called in debug builds.
+    ///
+    /// In release builds `f` is never called and `self` is returned untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Tap;
+    ///
+    /// let data: [u32; 5] = [2, 8, 3, 4, 0];
+    /// let tapped = data.tap_ref_dbg(|x| eprintln!("{x:?}"));
+    /// assert_eq!(tapped, [2, 8, 3, 4, 0]);
+    /// ```
+    fn tap_ref_dbg<F: FnOnce(&Self)>(self, f: F) -> Self;
+
+    /// Executes a fallible closure on a mutable reference to an object, returning it wrapped in
+    /// `Ok` on success.
+    ///
+    /// This inserts a fallible checkpoint (validation, I/O, parsing, ...) into an otherwise
+    /// infallible value chain: on `Ok(())` any mutations made by `f` are kept and `Ok(self)` is
+    /// returned; on `Err(e)` the value is dropped and `Err(e)` is returned, so the error can be
+    /// propagated with `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Tap;
+    ///
+    /// fn non_empty(v: &mut Vec<u32>) -> Result<(), &'static str> {
+    ///     if v.is_empty() {
+    ///         Err("must not be empty")
+    ///     } else {
+    ///         v.sort();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(vec![3, 1, 2].try_tap(non_empty), Ok(vec![1, 2, 3]));
+    /// assert_eq!(Vec::<u32>::new().try_tap(non_empty), Err("must not be empty"));
+    /// ```
+    fn try_tap<E, F: FnOnce(&mut Self) -> Result<(), E>>(self, f: F) -> Result<Self, E>
+    where
+        Self: Sized;
 }
 
 impl<T> Tap for T {
@@ -83,4 +169,249 @@ impl<T> Tap for T {
         f(&mut self);
         self
     }
+
+    #[inline]
+    fn tap_ref<F: FnOnce(&Self)>(self, f: F) -> Self {
+        f(&self);
+        self
+    }
+
+    #[inline]
+    fn tap_dbg<F: FnOnce(&mut Self)>(mut self, f: F) -> Self {
+        if cfg!(debug_assertions) {
+            f(&mut self);
+        }
+        self
+    }
+
+    #[inline]
+    fn tap_ref_dbg<F: FnOnce(&Self)>(self, f: F) -> Self {
+        if cfg!(debug_assertions) {
+            f(&self);
+        }
+        self
+    }
+
+    #[inline]
+    fn try_tap<E, F: FnOnce(&mut Self) -> Result<(), E>>(mut self, f: F) -> Result<Self, E> {
+        f(&mut self)?;
+        Ok(self)
+    }
+}
+
+/// An interface to enable tapping the [`Deref::Target`](core::ops::Deref::Target) of a value
+/// rather than the value itself.
+///
+/// Deref-coercion doesn't apply to named functions passed to a generic, so e.g.
+/// `vec.tap(Vec::sort)` fails to compile because `sort` is defined on `[T]`, not `Vec<T>`.
+/// `tap_deref` receives `&mut Self::Target`, letting slice (or other deref-target) methods be
+/// passed directly: `vec.tap_deref(<[_]>::sort)`.
+///
+/// # Examples
+///
+/// ```
+/// use tapir::TapDeref;
+///
+/// let v = vec![3, 1, 2].tap_deref(<[_]>::sort);
+/// assert_eq!(v, [1, 2, 3]);
+///
+/// let s = String::from("hi").tap_deref(str::make_ascii_uppercase);
+/// assert_eq!(s, "HI");
+/// ```
+pub trait TapDeref: DerefMut {
+    /// Executes a closure on a mutable reference to `Self::Target`, returning `self` afterwards.
+    fn tap_deref<F: FnOnce(&mut Self::Target)>(mut self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        f(&mut self);
+        self
+    }
+
+    /// Executes a closure on a shared reference to `Self::Target`, returning `self` afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::TapDeref;
+    ///
+    /// let mut len = 0;
+    /// let v = vec![1, 2, 3].tap_deref_ref(|s| len = s.len());
+    /// assert_eq!(len, 3);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    fn tap_deref_ref<F: FnOnce(&Self::Target)>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        f(&self);
+        self
+    }
+}
+
+impl<T: DerefMut> TapDeref for T {}
+
+/// An interface to enable the `pipe` operation which is implemented for all `Sized` types.
+///
+/// While [`Tap`] lets you inspect or mutate a value and hands back the same type, `pipe`
+/// threads a value through an arbitrary transforming function and hands back whatever that
+/// function returns. This allows chains like `x.pipe(f).pipe(g)` to read left-to-right instead
+/// of nesting as `g(f(x))`.
+///
+/// # Examples
+///
+/// ```
+/// use tapir::Pipe;
+///
+/// fn double(x: u32) -> u32 {
+///     x * 2
+/// }
+///
+/// fn stringify(x: u32) -> String {
+///     x.to_string()
+/// }
+///
+/// assert_eq!(21.pipe(double).pipe(stringify), "42");
+/// ```
+pub trait Pipe {
+    /// Passes `self` into `f` by value, returning the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Pipe;
+    ///
+    /// assert_eq!(2.pipe(|x| x * x), 4);
+    /// ```
+    fn pipe<R, F: FnOnce(Self) -> R>(self, f: F) -> R
+    where
+        Self: Sized,
+    {
+        f(self)
+    }
+
+    /// Passes `&self` into `f`, returning the result.
+    ///
+    /// Useful for calling a borrow-taking function mid-chain without rebinding the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Pipe;
+    ///
+    /// let v = vec![1, 2, 3];
+    /// assert_eq!(v.pipe_ref(Vec::len), 3);
+    /// ```
+    fn pipe_ref<R, F: FnOnce(&Self) -> R>(&self, f: F) -> R {
+        f(self)
+    }
+
+    /// Passes `&mut self` into `f`, returning the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::Pipe;
+    ///
+    /// let mut v = vec![3, 1, 2];
+    /// v.pipe_mut(|v| v.sort());
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    fn pipe_mut<R, F: FnOnce(&mut Self) -> R>(&mut self, f: F) -> R {
+        f(self)
+    }
+}
+
+impl<T> Pipe for T {}
+
+/// An interface to enable tapping the `Some`/`None` variants of an [`Option`].
+///
+/// # Examples
+///
+/// ```
+/// use tapir::TapOption;
+///
+/// let mut seen = None;
+/// let value: Option<u32> = Some(42).tap_some(|v| seen = Some(*v));
+/// assert_eq!(value, Some(42));
+/// assert_eq!(seen, Some(42));
+/// ```
+pub trait TapOption<T> {
+    /// Executes a closure on a shared reference to the contained value if it is `Some`,
+    /// returning the original `Option` unchanged.
+    fn tap_some<F: FnOnce(&T)>(self, f: F) -> Self;
+
+    /// Executes a closure if the value is `None`, returning the original `Option` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapir::TapOption;
+    ///
+    /// let mut was_none = false;
+    /// let value: Option<u32> = None.tap_none(|| was_none = true);
+    /// assert_eq!(value, None);
+    /// assert!(was_none);
+    /// ```
+    fn tap_none<F: FnOnce()>(self, f: F) -> Self;
+}
+
+impl<T> TapOption<T> for Option<T> {
+    #[inline]
+    fn tap_some<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Some(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn tap_none<F: FnOnce()>(self, f: F) -> Self {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+}
+
+/// An interface to enable tapping the `Ok`/`Err` variants of a [`Result`].
+///
+/// The classic use case is logging error information before discarding it, e.g.
+/// `result.tap_err(|e| eprintln!("bad: {e}")).ok()`.
+///
+/// # Examples
+///
+/// ```
+/// use tapir::TapResult;
+///
+/// let result: Result<u32, &str> = Err("oh no");
+/// let logged = result.tap_err(|e| eprintln!("bad: {e}"));
+/// assert_eq!(logged, Err("oh no"));
+/// ```
+pub trait TapResult<T, E> {
+    /// Executes a closure on a shared reference to the contained value if it is `Ok`,
+    /// returning the original `Result` unchanged.
+    fn tap_ok<F: FnOnce(&T)>(self, f: F) -> Self;
+
+    /// Executes a closure on a shared reference to the contained error if it is `Err`,
+    /// returning the original `Result` unchanged.
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self;
+}
+
+impl<T, E> TapResult<T, E> for Result<T, E> {
+    #[inline]
+    fn tap_ok<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Ok(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let Err(err) = &self {
+            f(err);
+        }
+        self
+    }
 }